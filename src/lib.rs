@@ -2,37 +2,315 @@ use std::{collections::HashMap, io::Write};
 
 use bevy_app::App;
 use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent, ReflectResource};
-use bevy_reflect::{TypeInfo, TypeRegistration, VariantInfo};
+use bevy_reflect::{NamedField, TypeInfo, TypeRegistration, UnnamedField, VariantInfo};
 use serde_json::{json, Value};
 
 pub trait ExportTypesExt {
-    fn export_types(&mut self, writer: impl Write);
+    /// Export every component and resource type in the `AppTypeRegistry`,
+    /// plus the types they reference. A shim over
+    /// [`export_types_with`](ExportTypesExt::export_types_with) using
+    /// [`ExportOptions::default`].
+    fn export_types(&mut self, writer: impl Write) {
+        self.export_types_with(writer, ExportOptions::default());
+    }
+
+    fn export_types_with(&mut self, writer: impl Write, options: ExportOptions);
 }
 
 impl ExportTypesExt for App {
-    fn export_types(&mut self, writer: impl Write) {
+    fn export_types_with(&mut self, writer: impl Write, options: ExportOptions) {
         let types = self.world.resource_mut::<AppTypeRegistry>();
         let types = types.read();
-        let mut schemas = types.iter().map(export_type).collect::<Vec<_>>();
-        schemas.sort_by_key(|t| t.get("name").unwrap().as_str().unwrap().to_string());
+
+        let is_root = |reg: &&TypeRegistration| {
+            let type_path = reg.type_info().type_path();
+            options.matches_kind(reg) && options.matches_path_filters(type_path)
+        };
+
+        let mut roots = types
+            .iter()
+            .filter(is_root)
+            .map(|reg| type_ref(reg.type_info().type_path()))
+            .collect::<Vec<_>>();
+        roots.sort_by_key(|r| r["$ref"].as_str().unwrap().to_string());
+
+        // Definitions for the root types, plus everything they transitively
+        // reference, so every `$ref` in the document stays resolvable even
+        // when path filters exclude most of the registry.
+        let mut defs = serde_json::Map::new();
+        let mut queue = types
+            .iter()
+            .filter(is_root)
+            .map(|reg| reg.type_info().type_path().to_owned())
+            .collect::<Vec<_>>();
+        while let Some(type_path) = queue.pop() {
+            if defs.contains_key(&type_path) {
+                continue;
+            }
+            let schema = if let Some(reg) = types.get_with_type_path(&type_path) {
+                let schema = export_type(reg, options.entity_as_name);
+                queue.extend(referenced_type_paths(&schema));
+                schema
+            } else if let Some(inner) = unwrap_option(&type_path) {
+                // `Option<T>` is never itself registered in the
+                // `AppTypeRegistry` -- only `T` is -- so resolve it to a
+                // `$ref` alias pointing straight at `T`'s definition instead
+                // of leaving this `$defs` entry unresolved.
+                queue.push(inner.to_owned());
+                type_ref(inner)
+            } else if let Some(schema) = primitive_schema(&type_path) {
+                // Scalars referenced by a field aren't necessarily
+                // registered themselves (e.g. `app.register_type::<Player>()`
+                // without also registering `f32`/`String`) -- fall back to
+                // the primitive mapping directly instead of leaving the
+                // `$ref` dangling.
+                schema
+            } else {
+                // Every other referenced type -- a nested custom struct, a
+                // `Vec<T>`/`[T; N]`/`HashMap<K, T>` field's own type, or its
+                // item type -- has to be registered for its `$ref` to resolve. Silently
+                // skipping it here would ship a schema with a dangling
+                // `$ref` and no way to notice, so fail loudly instead.
+                panic!(
+                    "cannot resolve `$ref` to `{type_path}`: it is not registered in the \
+                     `AppTypeRegistry` and isn't a primitive or `Option<T>`. Register it with \
+                     `app.register_type::<T>()`."
+                );
+            };
+            defs.insert(type_path, schema);
+        }
 
         serde_json::to_writer_pretty(
             writer,
             &json!({
                 "$schema": "https://json-schema.org/draft/2020-12/schema",
                 "title": "bevy game schema",
-                "oneOf": schemas,
+                "$defs": defs,
+                "oneOf": roots,
             }),
         )
         .expect("valid json");
 
-        eprintln!("wrote schema containing {} types", schemas.len());
+        eprintln!("wrote schema containing {} types", defs.len());
+    }
+}
+
+/// Options controlling which types [`ExportTypesExt::export_types_with`]
+/// includes and how it renders `Entity` fields. Construct with
+/// [`ExportOptions::default`] and adjust with the builder methods.
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    entity_as_name: bool,
+    components_only: bool,
+    resources_only: bool,
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            entity_as_name: true,
+            components_only: false,
+            resources_only: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Only export root types that have `#[reflect(Component)]`. Combines with
+    /// [`resources_only`](Self::resources_only) by union, not intersection.
+    pub fn components_only(mut self, components_only: bool) -> Self {
+        self.components_only = components_only;
+        self
+    }
+
+    /// Only export root types that have `#[reflect(Resource)]`. Combines with
+    /// [`components_only`](Self::components_only) by union, not intersection.
+    pub fn resources_only(mut self, resources_only: bool) -> Self {
+        self.resources_only = resources_only;
+        self
+    }
+
+    /// Emit `Entity` fields as named references instead of raw generational
+    /// indices. Defaults to `true`; set to `false` for games that serialize
+    /// raw entity IDs.
+    pub fn entity_as_name(mut self, entity_as_name: bool) -> Self {
+        self.entity_as_name = entity_as_name;
+        self
+    }
+
+    /// Only export root types whose type path starts with one of these
+    /// prefixes, e.g. `"my_game::"`. Empty (the default) includes every
+    /// type path.
+    pub fn include_paths(mut self, prefixes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.include = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Exclude root types whose type path starts with one of these
+    /// prefixes, e.g. `"bevy_render::"`.
+    pub fn exclude_paths(mut self, prefixes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.exclude = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn matches_kind(&self, reg: &TypeRegistration) -> bool {
+        let is_component = reg.data::<ReflectComponent>().is_some();
+        let is_resource = reg.data::<ReflectResource>().is_some();
+        match (self.components_only, self.resources_only) {
+            (false, false) => is_component || is_resource,
+            (true, false) => is_component,
+            (false, true) => is_resource,
+            (true, true) => is_component || is_resource,
+        }
+    }
+
+    fn matches_path_filters(&self, type_path: &str) -> bool {
+        let included = self.include.is_empty()
+            || self.include.iter().any(|prefix| type_path.starts_with(prefix.as_str()));
+        let excluded = self.exclude.iter().any(|prefix| type_path.starts_with(prefix.as_str()));
+        included && !excluded
     }
 }
 
-pub fn export_type(reg: &TypeRegistration) -> Value {
+/// Collect the `$defs` keys referenced by `$ref`s anywhere in `schema`, so
+/// the caller can pull those definitions in too and keep the document
+/// self-contained.
+fn referenced_type_paths(schema: &Value) -> Vec<String> {
+    fn walk(value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(reference) = map.get("$ref").and_then(Value::as_str) {
+                    if let Some(type_path) = reference.strip_prefix("#/$defs/") {
+                        out.push(type_path.to_owned());
+                    }
+                }
+                for value in map.values() {
+                    walk(value, out);
+                }
+            }
+            Value::Array(items) => {
+                for value in items {
+                    walk(value, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(schema, &mut out);
+    out
+}
+
+/// Build a `$ref` pointing at the `$defs` entry for `type_path`, using the
+/// same definition-and-pointer model as `schemafy`.
+fn type_ref(type_path: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{type_path}") })
+}
+
+/// If `type_path` is `core::option::Option<Inner>`, return `Inner`'s own
+/// type path. `Option<T>` is a "virtual" type: reflection never registers
+/// it separately from `T`, so it can't be resolved through the registry.
+fn unwrap_option(type_path: &str) -> Option<&str> {
+    type_path
+        .strip_prefix("core::option::Option<")
+        .and_then(|rest| rest.strip_suffix('>'))
+}
+
+/// Attach a reflected doc comment to a schema as `description`, plus a
+/// `deprecated` flag if the doc comment looks like a deprecation notice.
+fn describe(mut val: Value, docs: Option<&str>) -> Value {
+    let Some(docs) = docs else { return val };
+    let obj = val.as_object_mut().unwrap();
+    obj.insert("description".to_owned(), docs.trim().into());
+    if docs.to_lowercase().contains("deprecated") {
+        obj.insert("deprecated".to_owned(), true.into());
+    }
+    val
+}
+
+/// Map a Rust scalar's `type_path()` to the JSON Schema primitive it should
+/// serialize as, with `minimum`/`maximum` filled in for integers from the
+/// Rust type's own range. Returns `None` for leaf types that aren't scalars
+/// (e.g. opaque `TypeInfo::Value` types without a known mapping).
+fn primitive_schema(type_path: &str) -> Option<Value> {
+    fn integer(min: f64, max: f64) -> Value {
+        json!({ "type": "integer", "minimum": min, "maximum": max })
+    }
+
+    Some(match type_path {
+        "f32" | "f64" => json!({ "type": "number" }),
+        "bool" => json!({ "type": "boolean" }),
+        "char" | "str" | "alloc::string::String" => json!({ "type": "string" }),
+        "u8" => integer(u8::MIN as f64, u8::MAX as f64),
+        "u16" => integer(u16::MIN as f64, u16::MAX as f64),
+        "u32" => integer(u32::MIN as f64, u32::MAX as f64),
+        // `u64`/`u128`/`usize`/`i64`/`i128`/`isize` below: `as f64` can't
+        // represent these ranges exactly (e.g. `u64::MAX as f64` rounds up
+        // to 2^64, one past the true max), so their bounds are a close
+        // approximation rather than the exact Rust range.
+        "u64" => integer(u64::MIN as f64, u64::MAX as f64),
+        "u128" => integer(u128::MIN as f64, u128::MAX as f64),
+        "usize" => integer(usize::MIN as f64, usize::MAX as f64),
+        "i8" => integer(i8::MIN as f64, i8::MAX as f64),
+        "i16" => integer(i16::MIN as f64, i16::MAX as f64),
+        "i32" => integer(i32::MIN as f64, i32::MAX as f64),
+        "i64" => integer(i64::MIN as f64, i64::MAX as f64),
+        "i128" => integer(i128::MIN as f64, i128::MAX as f64),
+        "isize" => integer(isize::MIN as f64, isize::MAX as f64),
+        _ => return None,
+    })
+}
+
+/// Schema for a non-unit enum variant's externally-tagged serialization:
+/// a single-key object mapping the variant's name to `payload`.
+fn tagged_variant(name: &str, payload: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": { name: payload },
+        "additionalProperties": false,
+        "required": [name],
+    })
+}
+
+/// `Entity`'s own type path, as returned by `TypeInfo::type_path()`.
+const ENTITY_TYPE_PATH: &str = "bevy_ecs::entity::Entity";
+
+/// Schema for an `Entity` field that round-trips as a stable name rather
+/// than a raw generational index, matching how tools like the Blender
+/// components workflow register a "fake" named `Entity` type.
+fn entity_ref_schema(t: &TypeInfo) -> Value {
+    json!({
+        "name": t.type_path(),
+        "type": "string",
+        "x-entity-ref": true,
+    })
+}
+
+pub fn export_type(reg: &TypeRegistration, entity_as_name: bool) -> Value {
     let t = reg.type_info();
-    let mut schema = match t {
+    let mut schema = if entity_as_name && t.type_path() == ENTITY_TYPE_PATH {
+        entity_ref_schema(t)
+    } else {
+        export_type_schema(reg, t)
+    };
+    schema.as_object_mut().unwrap().insert(
+        "isComponent".to_owned(),
+        reg.data::<ReflectComponent>().is_some().into(),
+    );
+    schema.as_object_mut().unwrap().insert(
+        "isResource".to_owned(),
+        reg.data::<ReflectResource>().is_some().into(),
+    );
+    describe(schema, type_docs(t))
+}
+
+fn export_type_schema(reg: &TypeRegistration, t: &TypeInfo) -> Value {
+    match t {
         TypeInfo::Struct(info) => {
             let properties = info
                 .iter()
@@ -40,7 +318,10 @@ pub fn export_type(reg: &TypeRegistration) -> Value {
                 .map(|(idx, field)| {
                     (
                         field.name(),
-                        add_min_max(json!({ "type": field.type_path() }), reg, idx, None),
+                        describe(
+                            add_min_max(type_ref(field.type_path()), reg, idx, None),
+                            field_docs(field),
+                        ),
                     )
                 })
                 .collect::<HashMap<_, _>>();
@@ -74,42 +355,74 @@ pub fn export_type(reg: &TypeRegistration) -> Value {
                         .collect::<Vec<_>>(),
                 })
             } else {
+                // Bevy's reflect (de)serializer uses serde's externally-tagged
+                // representation: unit variants are a bare string, and
+                // tuple/struct variants are a single-key object mapping the
+                // variant name to its payload.
                 let variants = info
                 .iter()
                 .enumerate()
                 .map(|(field_idx, variant)| match variant {
-                    VariantInfo::Struct(v) => json!({
-                        "type": "object",
-                        "name": t.type_path(),
-                        "properties": v
+                    VariantInfo::Struct(v) => {
+                        let properties = v
                             .iter()
                             .enumerate()
-                            .map(|(variant_idx, field)| (field.name(), add_min_max(json!({"type": field.type_path(), "name": field.name()}), reg, field_idx, Some(variant_idx))))
-                            .collect::<HashMap<_, _>>(),
-                        "additionalProperties": false,
-                        "required": v
+                            .map(|(variant_idx, field)| {
+                                (
+                                    field.name(),
+                                    describe(
+                                        add_min_max(type_ref(field.type_path()), reg, field_idx, Some(variant_idx)),
+                                        field_docs(field),
+                                    ),
+                                )
+                            })
+                            .collect::<HashMap<_, _>>();
+                        let required = v
                             .iter()
-                            .filter(|field| field.type_path().starts_with("core::option::Option"))
+                            .filter(|field| !field.type_path().starts_with("core::option::Option"))
                             .map(|field| field.name())
-                            .collect::<Vec<_>>(),
-                    }),
-                    VariantInfo::Tuple(v) => json!({
-                        "type": "array",
-                        "prefixItems": v
+                            .collect::<Vec<_>>();
+                        tagged_variant(
+                            v.name(),
+                            json!({
+                                "type": "object",
+                                "properties": properties,
+                                "additionalProperties": false,
+                                "required": required,
+                            }),
+                        )
+                    }
+                    VariantInfo::Tuple(v) => {
+                        let fields = v
                             .iter()
                             .enumerate()
-                            .map(|(variant_idx, field)| add_min_max(json!({"type": field.type_path()}), reg, field_idx, Some(variant_idx)))
-                            .collect::<Vec<_>>(),
-                        "items": false,
-                    }),
+                            .map(|(variant_idx, field)| {
+                                describe(
+                                    add_min_max(type_ref(field.type_path()), reg, field_idx, Some(variant_idx)),
+                                    tuple_field_docs(field),
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        // A single-field tuple variant is a serde newtype
+                        // variant, which serializes as the bare inner value
+                        // rather than a one-element array.
+                        let payload = if let [field] = fields.as_slice() {
+                            field.clone()
+                        } else {
+                            json!({ "type": "array", "prefixItems": fields, "items": false })
+                        };
+                        tagged_variant(v.name(), payload)
+                    }
                     VariantInfo::Unit(v) => json!({
                         "const": v.name(),
                     }),
                 })
                 .collect::<Vec<_>>();
 
+                // No top-level "type": both unit variants (bare strings)
+                // and tuple/struct variants (single-key objects) appear in
+                // `oneOf`, so the wrapper itself can't commit to one type.
                 json!({
-                    "type": "object",
                     "name": t.type_path(),
                     "oneOf": variants,
                 })
@@ -121,7 +434,9 @@ pub fn export_type(reg: &TypeRegistration) -> Value {
             "prefixItems": info
                 .iter()
                 .enumerate()
-                .map(|(idx, field)| add_min_max(json!({"type": field.type_path()}), reg, idx, None))
+                .map(|(idx, field)| {
+                    describe(add_min_max(type_ref(field.type_path()), reg, idx, None), tuple_field_docs(field))
+                })
                 .collect::<Vec<_>>(),
             "items": false,
         }),
@@ -129,18 +444,18 @@ pub fn export_type(reg: &TypeRegistration) -> Value {
             json!({
                 "name": t.type_path(),
                 "type": "array",
-                "items": json!({"type": info.type_path()}),
+                "items": type_ref(info.item_type_path_table().path()),
             })
         }
         TypeInfo::Array(info) => json!({
             "name": t.type_path(),
             "type": "array",
-            "items": json!({"type": info.type_path()}),
+            "items": type_ref(info.item_type_path_table().path()),
         }),
         TypeInfo::Map(info) => json!({
             "name": t.type_path(),
             "type": "object",
-            "additionalProperties": json!({"type": info.type_path()}),
+            "additionalProperties": type_ref(info.value_type_path_table().path()),
         }),
         TypeInfo::Tuple(info) => json!({
             "name": t.type_path(),
@@ -148,24 +463,52 @@ pub fn export_type(reg: &TypeRegistration) -> Value {
             "prefixItems": info
                 .iter()
                 .enumerate()
-                .map(|(idx, field)| add_min_max(json!({"type": field.type_path()}), reg, idx, None))
+                .map(|(idx, field)| {
+                    describe(add_min_max(type_ref(field.type_path()), reg, idx, None), tuple_field_docs(field))
+                })
                 .collect::<Vec<_>>(),
             "items": false,
         }),
-        TypeInfo::Value(info) => json!({
-            "name": t.type_path(),
-            "type": info.type_path(),
-        }),
-    };
-    schema.as_object_mut().unwrap().insert(
-        "isComponent".to_owned(),
-        reg.data::<ReflectComponent>().is_some().into(),
-    );
-    schema.as_object_mut().unwrap().insert(
-        "isResource".to_owned(),
-        reg.data::<ReflectResource>().is_some().into(),
-    );
-    schema
+        TypeInfo::Value(info) => {
+            let mut schema = primitive_schema(info.type_path())
+                .unwrap_or_else(|| json!({ "type": info.type_path() }));
+            schema
+                .as_object_mut()
+                .unwrap()
+                .insert("name".to_owned(), t.type_path().into());
+            schema
+        }
+    }
+}
+
+#[cfg(feature = "documentation")]
+fn type_docs(info: &TypeInfo) -> Option<&str> {
+    info.docs()
+}
+
+#[cfg(not(feature = "documentation"))]
+fn type_docs(_info: &TypeInfo) -> Option<&str> {
+    None
+}
+
+#[cfg(feature = "documentation")]
+fn field_docs(field: &NamedField) -> Option<&str> {
+    field.docs()
+}
+
+#[cfg(not(feature = "documentation"))]
+fn field_docs(_field: &NamedField) -> Option<&str> {
+    None
+}
+
+#[cfg(feature = "documentation")]
+fn tuple_field_docs(field: &UnnamedField) -> Option<&str> {
+    field.docs()
+}
+
+#[cfg(not(feature = "documentation"))]
+fn tuple_field_docs(_field: &UnnamedField) -> Option<&str> {
+    None
 }
 
 fn add_min_max(
@@ -220,3 +563,131 @@ fn add_min_max(
     }
     val
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::Component;
+    use bevy_reflect::Reflect;
+
+    use super::*;
+
+    #[derive(Reflect, Component, Default)]
+    #[reflect(Component)]
+    struct WithOptional {
+        name: String,
+        nickname: Option<String>,
+    }
+
+    #[derive(Reflect, Default)]
+    struct Inner {
+        value: f32,
+    }
+
+    #[derive(Reflect, Component, Default)]
+    #[reflect(Component)]
+    struct WithNested {
+        inner: Inner,
+        many: Vec<Inner>,
+    }
+
+    #[derive(Reflect, Default)]
+    enum Mixed {
+        #[default]
+        Idle,
+        Moving(f32, f32),
+        Named {
+            label: String,
+        },
+    }
+
+    #[test]
+    fn struct_required_excludes_option_fields() {
+        let reg = TypeRegistration::of::<WithOptional>();
+        let schema = export_type(&reg, true);
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.iter().any(|f| f == "name"));
+        assert!(!required.iter().any(|f| f == "nickname"));
+    }
+
+    #[test]
+    fn enum_wrapper_has_no_top_level_type() {
+        // A mix of unit and non-unit variants means the wrapper can't
+        // commit to a single JSON Schema "type" -- unit variants are bare
+        // strings, the rest are single-key objects.
+        let reg = TypeRegistration::of::<Mixed>();
+        let schema = export_type(&reg, true);
+        assert!(schema.get("type").is_none());
+        let one_of = schema["oneOf"].as_array().unwrap();
+        assert!(one_of.contains(&json!({ "const": "Idle" })));
+    }
+
+    #[test]
+    fn struct_variant_is_externally_tagged() {
+        let reg = TypeRegistration::of::<Mixed>();
+        let schema = export_type(&reg, true);
+        let one_of = schema["oneOf"].as_array().unwrap();
+        let named = one_of
+            .iter()
+            .find(|v| v["properties"].get("Named").is_some())
+            .expect("Named variant present");
+        assert_eq!(named["required"], json!(["Named"]));
+        assert!(named["properties"]["Named"]["properties"]["label"].is_object());
+    }
+
+    #[test]
+    fn option_fields_do_not_produce_dangling_refs() {
+        let mut app = App::new();
+        app.register_type::<WithOptional>();
+        let mut buf = Vec::new();
+        app.export_types_with(&mut buf, ExportOptions::default());
+        let schema: Value = serde_json::from_slice(&buf).unwrap();
+
+        let defs = schema["$defs"].as_object().unwrap();
+        for type_path in referenced_type_paths(&schema) {
+            assert!(defs.contains_key(&type_path), "dangling $ref to {type_path}");
+        }
+    }
+
+    #[test]
+    fn nested_struct_and_vec_fields_do_not_produce_dangling_refs() {
+        let mut app = App::new();
+        app.register_type::<WithNested>();
+        app.register_type::<Inner>();
+        app.register_type::<Vec<Inner>>();
+        let mut buf = Vec::new();
+        app.export_types_with(&mut buf, ExportOptions::default());
+        let schema: Value = serde_json::from_slice(&buf).unwrap();
+
+        let defs = schema["$defs"].as_object().unwrap();
+        for type_path in referenced_type_paths(&schema) {
+            assert!(defs.contains_key(&type_path), "dangling $ref to {type_path}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Inner")]
+    fn unregistered_nested_struct_field_panics_instead_of_dangling() {
+        // `Inner` is reachable through `WithNested::inner` but is never
+        // registered itself -- this must fail loudly rather than ship a
+        // schema with a dangling `$ref`.
+        let mut app = App::new();
+        app.register_type::<WithNested>();
+        let mut buf = Vec::new();
+        app.export_types_with(&mut buf, ExportOptions::default());
+    }
+
+    #[test]
+    fn unwrap_option_extracts_inner_type_path() {
+        assert_eq!(unwrap_option("core::option::Option<f32>"), Some("f32"));
+        assert_eq!(unwrap_option("alloc::string::String"), None);
+    }
+
+    #[test]
+    fn primitive_schema_maps_integer_bounds() {
+        assert_eq!(
+            primitive_schema("u8"),
+            Some(json!({ "type": "integer", "minimum": 0.0, "maximum": 255.0 }))
+        );
+        assert_eq!(primitive_schema("bevy_ecs::entity::Entity"), None);
+    }
+}